@@ -0,0 +1,97 @@
+//! On-disk cache of previous lookups so repeated (or offline) lookups don't
+//! need to hit `api.dictionaryapi.dev`.
+
+use crate::DictionaryResponse;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Sanitize a word for safe use as a single filesystem path segment.
+/// Anything that isn't alphanumeric, `-`, or `_` (path separators, `..`,
+/// etc.) is replaced so a malicious or poisoned word can't escape the
+/// cache directory.
+fn sanitize_for_path(word: &str) -> String {
+    word.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Path to the cached JSON file for a word in a given language, under the
+/// user's cache directory. The language code is part of the path so the
+/// same spelling in different languages doesn't collide.
+fn cache_path(word: &str, lang: &str) -> PathBuf {
+    let mut dir = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+    dir.push("terminal_words");
+    dir.push(sanitize_for_path(lang));
+    dir.push(format!("{}.json", sanitize_for_path(&word.to_lowercase())));
+    dir
+}
+
+/// Age of a cached entry, if it exists.
+fn age(path: &PathBuf) -> Option<Duration> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    SystemTime::now().duration_since(modified).ok()
+}
+
+/// Load a word's cached entries, regardless of age.
+pub fn load(word: &str, lang: &str) -> Option<Vec<DictionaryResponse>> {
+    let contents = fs::read_to_string(cache_path(word, lang)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Load a word's cached entries only if they're not older than `ttl_days`
+/// (when given). A missing TTL means cached entries never go stale.
+pub fn load_fresh(word: &str, lang: &str, ttl_days: Option<u64>) -> Option<Vec<DictionaryResponse>> {
+    let path = cache_path(word, lang);
+    if let Some(days) = ttl_days {
+        let max_age = Duration::from_secs(days * 24 * 60 * 60);
+        if age(&path)? > max_age {
+            return None;
+        }
+    }
+    load(word, lang)
+}
+
+/// Persist a successful lookup to the cache.
+pub fn save(word: &str, lang: &str, definitions: &[DictionaryResponse]) -> io::Result<()> {
+    let path = cache_path(word, lang);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string(definitions)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_path_is_lowercased() {
+        let path = cache_path("HELLO", "en");
+        assert_eq!(path.file_name().unwrap().to_str().unwrap(), "hello.json");
+    }
+
+    #[test]
+    fn test_cache_path_scoped_by_language() {
+        let en = cache_path("chat", "en");
+        let fr = cache_path("chat", "fr");
+        assert_ne!(en, fr);
+    }
+
+    #[test]
+    fn test_load_fresh_uncached_word_is_none() {
+        // A word that was never cached simply returns None, even without a TTL.
+        assert!(load_fresh("definitely-not-a-cached-word", "en", None).is_none());
+    }
+
+    #[test]
+    fn test_cache_path_rejects_path_traversal() {
+        let base = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+        let path = cache_path("../../../../../../tmp/evil", "en");
+        assert!(path.starts_with(base.join("terminal_words").join("en")));
+        assert_eq!(path.components().count(), base.components().count() + 3);
+    }
+}
@@ -0,0 +1,372 @@
+//! Persistent flashcard deck with SM-2-style spaced repetition scheduling.
+
+use chrono::{Duration, NaiveDate, Utc};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+const STARTING_EASE: f32 = 2.5;
+const MINIMUM_EASE: f32 = 1.3;
+const HARD_EASE_PENALTY: f32 = 0.15;
+const FIRST_INTERVAL_DAYS: i64 = 1;
+const SECOND_INTERVAL_DAYS: i64 = 6;
+
+/// Self-graded recall quality, collected at the end of a review prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    Again,
+    Hard,
+    Good,
+}
+
+impl Grade {
+    fn parse(input: &str) -> Option<Grade> {
+        match input.trim().to_lowercase().as_str() {
+            "again" | "a" => Some(Grade::Again),
+            "hard" | "h" => Some(Grade::Hard),
+            "good" | "g" => Some(Grade::Good),
+            _ => None,
+        }
+    }
+}
+
+/// One flashcard: the looked-up word plus its SM-2 scheduling state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub word: String,
+    pub phonetic: String,
+    pub definition: String,
+    pub last_reviewed: Option<NaiveDate>,
+    pub interval_days: i64,
+    pub ease: f32,
+}
+
+impl Card {
+    /// A brand-new card for a word that was just looked up.
+    pub fn new(word: &str, phonetic: &str, definition: &str) -> Self {
+        Card {
+            word: word.to_string(),
+            phonetic: phonetic.to_string(),
+            definition: definition.to_string(),
+            last_reviewed: None,
+            interval_days: FIRST_INTERVAL_DAYS,
+            ease: STARTING_EASE,
+        }
+    }
+
+    /// Whether this card is due for review on `today` (new cards are always due).
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        match self.last_reviewed {
+            None => true,
+            Some(last) => last + Duration::days(self.interval_days) <= today,
+        }
+    }
+
+    /// Apply an SM-2-style update after a self-graded review.
+    pub fn apply_grade(&mut self, grade: Grade, today: NaiveDate) {
+        match grade {
+            Grade::Again => {
+                self.interval_days = FIRST_INTERVAL_DAYS;
+            }
+            Grade::Hard => {
+                self.ease = (self.ease - HARD_EASE_PENALTY).max(MINIMUM_EASE);
+                self.interval_days = (self.interval_days / 2).max(FIRST_INTERVAL_DAYS);
+            }
+            Grade::Good => {
+                self.interval_days = if self.last_reviewed.is_none() {
+                    FIRST_INTERVAL_DAYS
+                } else if self.interval_days <= FIRST_INTERVAL_DAYS {
+                    SECOND_INTERVAL_DAYS
+                } else {
+                    ((self.interval_days as f32) * self.ease).round() as i64
+                };
+            }
+        }
+        self.last_reviewed = Some(today);
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "- {}|{}|{}|{}|{}|{:.2}",
+            escape_field(&self.word),
+            escape_field(&self.phonetic),
+            escape_field(&self.definition),
+            self.last_reviewed
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            self.interval_days,
+            self.ease
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Card> {
+        let rest = line.strip_prefix("- ")?;
+        let fields = split_fields(rest);
+        if fields.len() != 6 {
+            return None;
+        }
+
+        let last_reviewed = if fields[3].is_empty() {
+            None
+        } else {
+            NaiveDate::parse_from_str(&fields[3], "%Y-%m-%d").ok()
+        };
+
+        Some(Card {
+            word: fields[0].clone(),
+            phonetic: fields[1].clone(),
+            definition: fields[2].clone(),
+            last_reviewed,
+            interval_days: fields[4].parse().ok()?,
+            ease: fields[5].parse().ok()?,
+        })
+    }
+}
+
+/// Escape `\`, `|`, and newlines so a field can't be mistaken for a field
+/// boundary or corrupt the newline-delimited deck format.
+fn escape_field(field: &str) -> String {
+    field.replace('\\', "\\\\").replace('|', "\\|").replace('\n', "\\n")
+}
+
+/// Undo the escape sequences produced by `escape_field` in one field.
+fn unescape_field(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some('|') => out.push('|'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Split a `|`-delimited line into its (unescaped) fields, treating `\|` as
+/// a literal pipe rather than a field boundary.
+fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '|' => {
+                fields.push(unescape_field(&current));
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    fields.push(unescape_field(&current));
+
+    fields
+}
+
+/// Path to the deck file in the user's data directory.
+fn deck_path() -> PathBuf {
+    let mut dir = dirs::data_dir().unwrap_or_else(std::env::temp_dir);
+    dir.push("terminal_words");
+    dir.push("deck.txt");
+    dir
+}
+
+/// Load all cards currently in the deck, ignoring comment and blank lines.
+pub fn load_deck() -> io::Result<Vec<Card>> {
+    let path = deck_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .filter_map(Card::from_line)
+        .collect())
+}
+
+fn save_deck(cards: &[Card]) -> io::Result<()> {
+    let path = deck_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut contents = String::from("# terminal_words flashcard deck\n");
+    contents.push_str("# format: - word|phonetic|definition|last_reviewed|interval_days|ease\n");
+    for card in cards {
+        contents.push_str(&card.to_line());
+        contents.push('\n');
+    }
+
+    fs::write(path, contents)
+}
+
+/// Append a newly looked-up word to the deck, skipping it if it's already there.
+pub fn save_word(word: &str, phonetic: &str, definition: &str) -> io::Result<()> {
+    let mut cards = load_deck()?;
+    if cards.iter().any(|c| c.word.eq_ignore_ascii_case(word)) {
+        return Ok(());
+    }
+
+    cards.push(Card::new(word, phonetic, definition));
+    save_deck(&cards)
+}
+
+/// Run an interactive review session over all cards due today.
+pub fn run_review() -> io::Result<()> {
+    let mut cards = load_deck()?;
+    let today = Utc::now().date_naive();
+    let due: Vec<usize> = cards
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.is_due(today))
+        .map(|(i, _)| i)
+        .collect();
+
+    if due.is_empty() {
+        println!("No cards due for review. 🎉");
+        return Ok(());
+    }
+
+    println!("{} card(s) due for review.\n", due.len());
+
+    for index in due {
+        let word = cards[index].word.clone();
+        let definition = cards[index].definition.clone();
+
+        print!("Word: {} (press Enter to reveal) ", word);
+        io::stdout().flush()?;
+        let mut buf = String::new();
+        io::stdin().read_line(&mut buf)?;
+
+        println!("Definition: {}\n", definition);
+
+        let grade = loop {
+            print!("How did you do? (again/hard/good) ");
+            io::stdout().flush()?;
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+            match Grade::parse(&input) {
+                Some(grade) => break grade,
+                None => println!("Please answer with again, hard, or good."),
+            }
+        };
+
+        cards[index].apply_grade(grade, today);
+        println!();
+    }
+
+    save_deck(&cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_new_card_is_due() {
+        let card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        assert!(card.is_due(date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_good_grade_schedule() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        let today = date(2026, 1, 1);
+        card.apply_grade(Grade::Good, today);
+        assert_eq!(card.interval_days, FIRST_INTERVAL_DAYS);
+
+        card.apply_grade(Grade::Good, today);
+        assert_eq!(card.interval_days, SECOND_INTERVAL_DAYS);
+
+        card.apply_grade(Grade::Good, today);
+        assert_eq!(card.interval_days, (SECOND_INTERVAL_DAYS as f32 * STARTING_EASE).round() as i64);
+    }
+
+    #[test]
+    fn test_hard_grade_shrinks_interval_and_ease() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        card.interval_days = 10;
+        card.apply_grade(Grade::Hard, date(2026, 1, 1));
+        assert_eq!(card.interval_days, 5);
+        assert_eq!(card.ease, STARTING_EASE - HARD_EASE_PENALTY);
+    }
+
+    #[test]
+    fn test_hard_grade_respects_ease_floor() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        card.ease = MINIMUM_EASE;
+        card.apply_grade(Grade::Hard, date(2026, 1, 1));
+        assert_eq!(card.ease, MINIMUM_EASE);
+    }
+
+    #[test]
+    fn test_again_grade_resets_interval() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        card.interval_days = 30;
+        card.apply_grade(Grade::Again, date(2026, 1, 1));
+        assert_eq!(card.interval_days, FIRST_INTERVAL_DAYS);
+    }
+
+    #[test]
+    fn test_card_line_roundtrip() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        card.apply_grade(Grade::Good, date(2026, 1, 1));
+        let line = card.to_line();
+        let parsed = Card::from_line(&line).unwrap();
+        assert_eq!(parsed, card);
+    }
+
+    #[test]
+    fn test_is_due_false_before_interval_elapses() {
+        let mut card = Card::new("apple", "/ˈæp.əl/", "A fruit");
+        card.apply_grade(Grade::Good, date(2026, 1, 1));
+        assert!(!card.is_due(date(2026, 1, 1)));
+        assert!(card.is_due(date(2026, 1, 2)));
+    }
+
+    #[test]
+    fn test_grade_parse() {
+        assert_eq!(Grade::parse("good"), Some(Grade::Good));
+        assert_eq!(Grade::parse("H"), Some(Grade::Hard));
+        assert_eq!(Grade::parse("nonsense"), None);
+    }
+
+    #[test]
+    fn test_card_line_roundtrip_with_pipe_in_definition() {
+        let card = Card::new("pipe", "/paɪp/", "a term using the | pipe symbol in prose");
+        let line = card.to_line();
+        let parsed = Card::from_line(&line).unwrap();
+        assert_eq!(parsed, card);
+    }
+
+    #[test]
+    fn test_card_line_roundtrip_with_backslash_and_newline() {
+        let card = Card::new("path", "C:\\dir\\file", "a line\nwith a break and a \\ backslash");
+        let line = card.to_line();
+        assert_eq!(line.lines().count(), 1, "escaped fields must stay on one line");
+        let parsed = Card::from_line(&line).unwrap();
+        assert_eq!(parsed, card);
+    }
+}
@@ -0,0 +1,180 @@
+//! Structured export formats for dictionary results: JSON, CSV, Markdown,
+//! and Anki-importable TSV, as an alternative to the colored terminal view.
+
+use crate::DictionaryResponse;
+use clap::ValueEnum;
+
+/// Output rendering selected via `--output`/`-o`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored terminal rendering (the default).
+    Pretty,
+    /// Re-emit the parsed API response as JSON.
+    Json,
+    /// Flatten to `word,part_of_speech,definition,example` rows.
+    Csv,
+    /// Headings and bullet lists.
+    Markdown,
+    /// Tab-separated `front<TAB>back` records for flashcard import.
+    Anki,
+}
+
+/// Render a word's dictionary entries in the given non-pretty format.
+pub fn render(definitions: &[DictionaryResponse], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Pretty => unreachable!("pretty output is rendered by display_word_info"),
+        OutputFormat::Json => to_json(definitions),
+        OutputFormat::Csv => to_csv(definitions),
+        OutputFormat::Markdown => to_markdown(definitions),
+        OutputFormat::Anki => to_anki(definitions),
+    }
+}
+
+fn to_json(definitions: &[DictionaryResponse]) -> String {
+    serde_json::to_string_pretty(definitions).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+}
+
+/// Quote a CSV field per RFC 4180 if it contains a comma, quote, or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains(['"', ',', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(definitions: &[DictionaryResponse]) -> String {
+    let mut lines = vec!["word,part_of_speech,definition,example".to_string()];
+
+    for response in definitions {
+        for meaning in &response.meanings {
+            let pos = meaning.part_of_speech.as_deref().unwrap_or("");
+            for def in &meaning.definitions {
+                lines.push(format!(
+                    "{},{},{},{}",
+                    csv_field(&response.word),
+                    csv_field(pos),
+                    csv_field(&def.definition),
+                    csv_field(def.example.as_deref().unwrap_or(""))
+                ));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn to_markdown(definitions: &[DictionaryResponse]) -> String {
+    let mut out = String::new();
+
+    for response in definitions {
+        out.push_str(&format!("## {}\n\n", response.word));
+
+        if let Some(phonetic) = &response.phonetic {
+            out.push_str(&format!("*{}*\n\n", phonetic));
+        }
+
+        for meaning in &response.meanings {
+            let pos = meaning.part_of_speech.as_deref().unwrap_or("unknown");
+            out.push_str(&format!("### {}\n\n", pos));
+
+            for def in &meaning.definitions {
+                out.push_str(&format!("- {}\n", def.definition));
+                if let Some(example) = &def.example {
+                    out.push_str(&format!("  - *Example:* {}\n", example));
+                }
+            }
+            out.push('\n');
+        }
+    }
+
+    out.trim_end().to_string()
+}
+
+fn to_anki(definitions: &[DictionaryResponse]) -> String {
+    let mut lines = Vec::new();
+
+    for response in definitions {
+        let phonetic = response.phonetic.as_deref().unwrap_or("");
+        for meaning in &response.meanings {
+            let pos = meaning.part_of_speech.as_deref().unwrap_or("unknown");
+            for def in &meaning.definitions {
+                let mut back = format!("<b>{}</b>: {}", pos, def.definition);
+                if !phonetic.is_empty() {
+                    back.push_str(&format!("<br><i>{}</i>", phonetic));
+                }
+                if let Some(example) = &def.example {
+                    back.push_str(&format!("<br>Example: {}", example));
+                }
+                lines.push(format!("{}\t{}", response.word, back));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Definition, Meaning};
+
+    fn sample() -> Vec<DictionaryResponse> {
+        vec![DictionaryResponse {
+            word: "cat".to_string(),
+            phonetic: Some("/kæt/".to_string()),
+            phonetics: None,
+            meanings: vec![Meaning {
+                part_of_speech: Some("noun".to_string()),
+                definitions: vec![Definition {
+                    definition: "A small domesticated animal".to_string(),
+                    example: Some("The cat sat on the mat".to_string()),
+                    synonyms: None,
+                    antonyms: None,
+                }],
+                synonyms: None,
+                antonyms: None,
+            }],
+            license: None,
+            source_urls: None,
+        }]
+    }
+
+    #[test]
+    fn test_to_csv_includes_header_and_row() {
+        let csv = to_csv(&sample());
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("word,part_of_speech,definition,example"));
+        assert_eq!(
+            lines.next(),
+            Some("cat,noun,A small domesticated animal,The cat sat on the mat")
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quotes_commas() {
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("plain"), "plain");
+    }
+
+    #[test]
+    fn test_to_markdown_has_heading_and_bullet() {
+        let md = to_markdown(&sample());
+        assert!(md.contains("## cat"));
+        assert!(md.contains("- A small domesticated animal"));
+    }
+
+    #[test]
+    fn test_to_anki_is_tab_separated() {
+        let tsv = to_anki(&sample());
+        let (front, back) = tsv.split_once('\t').unwrap();
+        assert_eq!(front, "cat");
+        assert!(back.contains("A small domesticated animal"));
+    }
+
+    #[test]
+    fn test_to_json_roundtrips_word() {
+        let json = to_json(&sample());
+        assert!(json.contains("\"word\": \"cat\""));
+    }
+}
@@ -0,0 +1,60 @@
+//! Supported dictionary language codes for `--lang`/`-l`.
+
+/// Language codes accepted by `api.dictionaryapi.dev`, paired with their
+/// human-readable names.
+const LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("es", "Spanish"),
+    ("fr", "French"),
+    ("de", "German"),
+    ("it", "Italian"),
+    ("pt", "Portuguese"),
+    ("ru", "Russian"),
+    ("ja", "Japanese"),
+    ("ar", "Arabic"),
+    ("hi", "Hindi"),
+    ("ko", "Korean"),
+    ("tr", "Turkish"),
+];
+
+/// Whether `code` is a recognized language code.
+pub fn is_supported(code: &str) -> bool {
+    LANGUAGES.iter().any(|(c, _)| *c == code)
+}
+
+/// Print every supported language code and name.
+pub fn print_supported() {
+    println!("Supported language codes:");
+    for (code, name) in LANGUAGES {
+        println!("  {:<4} {}", code, name);
+    }
+}
+
+/// A comma-separated list of valid codes, for error messages.
+pub fn valid_codes() -> String {
+    LANGUAGES
+        .iter()
+        .map(|(code, _)| *code)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported_known_code() {
+        assert!(is_supported("fr"));
+    }
+
+    #[test]
+    fn test_is_supported_unknown_code() {
+        assert!(!is_supported("xx"));
+    }
+
+    #[test]
+    fn test_valid_codes_contains_en() {
+        assert!(valid_codes().contains("en"));
+    }
+}
@@ -1,15 +1,25 @@
 use clap::Parser;
 use colored::*;
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
+
+mod cache;
+mod deck;
+mod export;
+mod lang;
+mod suggest;
+
+use export::OutputFormat;
 
 #[derive(Parser)]
 #[command(name = "terminal_words")]
 #[command(about = "A command-line dictionary tool", long_about = None)]
 struct Cli {
-    /// Word to look up
-    word: Option<String>,
-    
+    /// Word(s) to look up. Pass several for a batch lookup, or "-" to read
+    /// whitespace/newline-separated words from stdin.
+    words: Vec<String>,
+
     /// Show detailed information (all definitions, examples, synonyms, antonyms)
     #[arg(short, long)]
     detail: bool,
@@ -21,59 +31,156 @@ struct Cli {
     /// Maximum number of definitions to show per part of speech (default: 3, use -d for all)
     #[arg(short = 'n', long, default_value = "3")]
     limit: usize,
+
+    /// Save looked-up words to the flashcard deck for later review
+    #[arg(short, long)]
+    save: bool,
+
+    /// Review due flashcards from the deck instead of looking up a word
+    #[arg(long)]
+    review: bool,
+
+    /// Output format: pretty (default), json, csv, markdown, or anki
+    #[arg(short, long, value_enum, default_value = "pretty")]
+    output: OutputFormat,
+
+    /// Restrict lookups to the local cache; error if a word isn't cached
+    #[arg(long)]
+    offline: bool,
+
+    /// Force a network fetch even if a fresh cached entry exists
+    #[arg(long)]
+    refresh: bool,
+
+    /// Treat cached entries older than this many days as stale
+    #[arg(long)]
+    cache_ttl: Option<u64>,
+
+    /// Language code to look up definitions in (see --list-langs)
+    #[arg(short = 'l', long, default_value = "en")]
+    lang: String,
+
+    /// List supported language codes and exit
+    #[arg(long)]
+    list_langs: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct DictionaryResponse {
-    word: String,
-    phonetic: Option<String>,
-    phonetics: Option<Vec<Phonetic>>,
-    meanings: Vec<Meaning>,
-    license: Option<License>,
-    source_urls: Option<Vec<String>>,
+pub(crate) struct DictionaryResponse {
+    pub(crate) word: String,
+    pub(crate) phonetic: Option<String>,
+    pub(crate) phonetics: Option<Vec<Phonetic>>,
+    pub(crate) meanings: Vec<Meaning>,
+    pub(crate) license: Option<License>,
+    pub(crate) source_urls: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct Phonetic {
+pub(crate) struct Phonetic {
     text: Option<String>,
     audio: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct Meaning {
-    part_of_speech: Option<String>,
-    definitions: Vec<Definition>,
-    synonyms: Option<Vec<String>>,
-    antonyms: Option<Vec<String>>,
+pub(crate) struct Meaning {
+    pub(crate) part_of_speech: Option<String>,
+    pub(crate) definitions: Vec<Definition>,
+    pub(crate) synonyms: Option<Vec<String>>,
+    pub(crate) antonyms: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct Definition {
-    definition: String,
-    example: Option<String>,
-    synonyms: Option<Vec<String>>,
-    antonyms: Option<Vec<String>>,
+pub(crate) struct Definition {
+    pub(crate) definition: String,
+    pub(crate) example: Option<String>,
+    pub(crate) synonyms: Option<Vec<String>>,
+    pub(crate) antonyms: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct License {
+pub(crate) struct License {
     name: String,
     url: String,
 }
 
-async fn lookup_word(word: &str) -> Result<Vec<DictionaryResponse>, Box<dyn std::error::Error>> {
-    let url = format!("https://api.dictionaryapi.dev/api/v2/entries/en/{}"
-, word);
-    
-    let response = reqwest::get(&url).await?;
-    
-    if response.status().is_success() {
-        let definitions: Vec<DictionaryResponse> = response.json().await?;
-        Ok(definitions)
+/// Error from a dictionary lookup, distinguishing a plain 404 (so callers
+/// can offer "did you mean" suggestions) from other API failures and from
+/// an `--offline` lookup that missed the cache.
+#[derive(Debug)]
+enum LookupError {
+    NotFound,
+    NotCached,
+    Api(String),
+}
+
+impl std::fmt::Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::NotFound => write!(f, "Word not found"),
+            LookupError::NotCached => write!(f, "Not cached, and --offline is set"),
+            LookupError::Api(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+async fn lookup_word(word: &str, lang: &str) -> Result<Vec<DictionaryResponse>, LookupError> {
+    let url = format!("https://api.dictionaryapi.dev/api/v2/entries/{}/{}", lang, word);
+
+    let response = reqwest::get(&url)
+        .await
+        .map_err(|e| LookupError::Api(e.to_string()))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        Err(LookupError::NotFound)
+    } else if response.status().is_success() {
+        response
+            .json()
+            .await
+            .map_err(|e| LookupError::Api(e.to_string()))
     } else {
-        Err("Word not found or API error".into())
+        Err(LookupError::Api(format!(
+            "API error: {}",
+            response.status()
+        )))
+    }
+}
+
+/// Cache-related flags bundled together, mirroring `DisplayOptions`.
+struct CacheOptions {
+    /// Restrict lookups to the local cache, erroring on a miss.
+    offline: bool,
+    /// Force a network fetch even if a fresh cached entry exists.
+    refresh: bool,
+    /// Treat cached entries older than this many days as stale.
+    ttl_days: Option<u64>,
+}
+
+/// Resolve a word in the given language via the cache-first policy in
+/// `CacheOptions`, falling back to the network and populating the cache on
+/// a miss.
+async fn resolve_word(
+    word: &str,
+    lang: &str,
+    cache_opts: &CacheOptions,
+) -> Result<Vec<DictionaryResponse>, LookupError> {
+    if cache_opts.offline {
+        return cache::load_fresh(word, lang, cache_opts.ttl_days).ok_or(LookupError::NotCached);
+    }
+
+    if !cache_opts.refresh {
+        if let Some(cached) = cache::load_fresh(word, lang, cache_opts.ttl_days) {
+            return Ok(cached);
+        }
+    }
+
+    let definitions = lookup_word(word, lang).await?;
+    if let Err(e) = cache::save(word, lang, &definitions) {
+        eprintln!("Warning: could not update cache: {}", e);
     }
+    Ok(definitions)
 }
 
 /// Format a non-empty list as "label item1, item2, ..." or None if empty/missing
@@ -160,22 +267,136 @@ fn display_word_info(response: &DictionaryResponse, options: &DisplayOptions) {
     }
 }
 
-async fn lookup_and_display(word: &str, options: &DisplayOptions) {
-    println!("{} {}", "Looking up:".bright_green(), word.bright_white().bold());
-    
-    match lookup_word(word).await {
+/// The first definition text found across a word's dictionary entries, used
+/// when saving a flashcard.
+fn first_definition(definitions: &[DictionaryResponse]) -> Option<&str> {
+    definitions
+        .iter()
+        .flat_map(|d| d.meanings.iter())
+        .flat_map(|m| m.definitions.iter())
+        .map(|d| d.definition.as_str())
+        .next()
+}
+
+/// Print the "Looking up: ..." header and the outcome of a single lookup,
+/// optionally saving a successful result to the flashcard deck. Shared by
+/// the single-word path, interactive mode, and batch lookups.
+///
+/// For `OutputFormat::Pretty` this prints the usual colored status lines;
+/// for any other format those are suppressed so the output stays pipeable,
+/// and the result is rendered in the selected structured format instead.
+fn show_lookup_result(
+    word: &str,
+    result: Result<Vec<DictionaryResponse>, LookupError>,
+    options: &DisplayOptions,
+    save: bool,
+    format: OutputFormat,
+) {
+    if format == OutputFormat::Pretty {
+        println!("{} {}", "Looking up:".bright_green(), word.bright_white().bold());
+    }
+
+    match result {
         Ok(definitions) => {
-            for definition in definitions {
-                display_word_info(&definition, options);
+            if format == OutputFormat::Pretty {
+                for definition in &definitions {
+                    display_word_info(definition, options);
+                }
+            } else {
+                println!("{}", export::render(&definitions, format));
+            }
+
+            if save {
+                let phonetic = definitions
+                    .first()
+                    .and_then(|d| d.phonetic.as_deref())
+                    .unwrap_or("");
+                let definition_text = first_definition(&definitions).unwrap_or("");
+                if let Err(e) = deck::save_word(word, phonetic, definition_text) {
+                    eprintln!("Warning: could not save to deck: {}", e);
+                }
+            }
+        }
+        Err(LookupError::NotFound) => {
+            if format == OutputFormat::Pretty {
+                println!("{} {}", "Error:".bright_red().bold(), "Word not found".bright_red());
+
+                let suggestions = suggest::suggest(word);
+                if !suggestions.is_empty() {
+                    println!("{} {}", "Did you mean:".bright_yellow().bold(), suggestions.join(", ").bright_cyan());
+                }
+            } else {
+                eprintln!("{}: word not found", word);
             }
         }
         Err(e) => {
-            println!("{} {}", "Error:".bright_red().bold(), e.to_string().bright_red());
+            if format == OutputFormat::Pretty {
+                println!("{} {}", "Error:".bright_red().bold(), e.to_string().bright_red());
+            } else {
+                eprintln!("{}: {}", word, e);
+            }
         }
     }
 }
 
-async fn run_interactive_mode(options: &DisplayOptions) {
+async fn lookup_and_display(
+    word: &str,
+    lang: &str,
+    options: &DisplayOptions,
+    save: bool,
+    format: OutputFormat,
+    cache_opts: &CacheOptions,
+) {
+    let result = resolve_word(word, lang, cache_opts).await;
+    show_lookup_result(word, result, options, save, format);
+}
+
+/// Resolve a batch of words concurrently, with bounded parallelism, and
+/// print each result grouped in input order even though requests complete
+/// out of order. A failure for one word doesn't abort the rest of the batch.
+async fn run_batch(
+    words: Vec<String>,
+    lang: &str,
+    options: &DisplayOptions,
+    save: bool,
+    format: OutputFormat,
+    cache_opts: &CacheOptions,
+) {
+    let concurrency = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4);
+
+    let mut results: Vec<(usize, String, Result<Vec<DictionaryResponse>, LookupError>)> =
+        stream::iter(words.into_iter().enumerate())
+            .map(|(index, word)| async move {
+                let result = resolve_word(&word, lang, cache_opts).await;
+                (index, word, result)
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+    results.sort_by_key(|(index, _, _)| *index);
+
+    for (_, word, result) in results {
+        show_lookup_result(&word, result, options, save, format);
+    }
+}
+
+/// Read whitespace/newline-separated words from stdin, for `sw -`.
+fn read_words_from_stdin() -> io::Result<Vec<String>> {
+    let mut buf = String::new();
+    io::stdin().read_to_string(&mut buf)?;
+    Ok(buf.split_whitespace().map(|s| s.to_string()).collect())
+}
+
+async fn run_interactive_mode(
+    lang: &str,
+    options: &DisplayOptions,
+    save: bool,
+    format: OutputFormat,
+    cache_opts: &CacheOptions,
+) {
     println!("{}", "🔄 Interactive Mode".bright_cyan().bold());
     println!("{}", "Type a word to look up, or 'q'/'quit'/'exit' to exit.".bright_blue());
     println!();
@@ -204,7 +425,7 @@ async fn run_interactive_mode(options: &DisplayOptions) {
                     break;
                 }
                 
-                lookup_and_display(word, options).await;
+                lookup_and_display(word, lang, options, save, format, cache_opts).await;
             }
             Err(e) => {
                 println!("{} {}", "Error reading input:".bright_red(), e);
@@ -227,13 +448,40 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         detailed: cli.detail,
         limit: cli.limit,
     };
-    
-    if cli.interactive {
+    let format = cli.output;
+    let cache_opts = CacheOptions {
+        offline: cli.offline,
+        refresh: cli.refresh,
+        ttl_days: cli.cache_ttl,
+    };
+
+    if cli.list_langs {
+        // List supported language codes and exit
+        lang::print_supported();
+    } else if !lang::is_supported(&cli.lang) {
+        println!(
+            "{} {} is not a supported language code.",
+            "Error:".bright_red().bold(),
+            cli.lang
+        );
+        println!("{} {}", "Valid codes:".bright_yellow(), lang::valid_codes());
+        std::process::exit(1);
+    } else if cli.review {
+        // Review due flashcards
+        deck::run_review()?;
+    } else if cli.interactive {
         // Interactive mode
-        run_interactive_mode(&options).await;
-    } else if let Some(word) = cli.word {
+        run_interactive_mode(&cli.lang, &options, cli.save, format, &cache_opts).await;
+    } else if cli.words == vec!["-".to_string()] {
+        // Batch mode reading words from stdin
+        let words = read_words_from_stdin()?;
+        run_batch(words, &cli.lang, &options, cli.save, format, &cache_opts).await;
+    } else if cli.words.len() == 1 {
         // Single word lookup mode
-        lookup_and_display(&word, &options).await;
+        lookup_and_display(&cli.words[0], &cli.lang, &options, cli.save, format, &cache_opts).await;
+    } else if !cli.words.is_empty() {
+        // Batch lookup mode
+        run_batch(cli.words, &cli.lang, &options, cli.save, format, &cache_opts).await;
     } else {
         // No word provided and not in interactive mode
         println!("{}", "Error: Please provide a word to look up, or use -i for interactive mode.".bright_red());
@@ -254,7 +502,7 @@ mod tests {
     #[test]
     fn test_cli_with_word() {
         let cli = Cli::try_parse_from(["sw", "hello"]).unwrap();
-        assert_eq!(cli.word, Some("hello".to_string()));
+        assert_eq!(cli.words, vec!["hello".to_string()]);
         assert!(!cli.detail);
         assert!(!cli.interactive);
         assert_eq!(cli.limit, 3); // default limit
@@ -263,7 +511,7 @@ mod tests {
     #[test]
     fn test_cli_with_detail_flag() {
         let cli = Cli::try_parse_from(["sw", "hello", "-d"]).unwrap();
-        assert_eq!(cli.word, Some("hello".to_string()));
+        assert_eq!(cli.words, vec!["hello".to_string()]);
         assert!(cli.detail);
         assert!(!cli.interactive);
     }
@@ -271,7 +519,7 @@ mod tests {
     #[test]
     fn test_cli_with_interactive_flag() {
         let cli = Cli::try_parse_from(["sw", "-i"]).unwrap();
-        assert_eq!(cli.word, None);
+        assert!(cli.words.is_empty());
         assert!(!cli.detail);
         assert!(cli.interactive);
     }
@@ -279,7 +527,7 @@ mod tests {
     #[test]
     fn test_cli_with_interactive_and_detail_flags() {
         let cli = Cli::try_parse_from(["sw", "-i", "-d"]).unwrap();
-        assert_eq!(cli.word, None);
+        assert!(cli.words.is_empty());
         assert!(cli.detail);
         assert!(cli.interactive);
     }
@@ -287,7 +535,7 @@ mod tests {
     #[test]
     fn test_cli_no_args() {
         let cli = Cli::try_parse_from(["sw"]).unwrap();
-        assert_eq!(cli.word, None);
+        assert!(cli.words.is_empty());
         assert!(!cli.detail);
         assert!(!cli.interactive);
         assert_eq!(cli.limit, 3); // default limit
@@ -296,25 +544,87 @@ mod tests {
     #[test]
     fn test_cli_with_limit_flag() {
         let cli = Cli::try_parse_from(["sw", "hello", "-n", "5"]).unwrap();
-        assert_eq!(cli.word, Some("hello".to_string()));
+        assert_eq!(cli.words, vec!["hello".to_string()]);
         assert_eq!(cli.limit, 5);
     }
 
     #[test]
     fn test_cli_with_limit_long_flag() {
         let cli = Cli::try_parse_from(["sw", "hello", "--limit", "10"]).unwrap();
-        assert_eq!(cli.word, Some("hello".to_string()));
+        assert_eq!(cli.words, vec!["hello".to_string()]);
         assert_eq!(cli.limit, 10);
     }
 
     #[test]
     fn test_cli_with_limit_and_detail() {
         let cli = Cli::try_parse_from(["sw", "hello", "-n", "2", "-d"]).unwrap();
-        assert_eq!(cli.word, Some("hello".to_string()));
+        assert_eq!(cli.words, vec!["hello".to_string()]);
         assert_eq!(cli.limit, 2);
         assert!(cli.detail); // detail mode ignores limit
     }
 
+    #[test]
+    fn test_cli_with_multiple_words() {
+        let cli = Cli::try_parse_from(["sw", "apple", "banana", "cherry"]).unwrap();
+        assert_eq!(
+            cli.words,
+            vec!["apple".to_string(), "banana".to_string(), "cherry".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_cli_with_stdin_marker() {
+        let cli = Cli::try_parse_from(["sw", "-"]).unwrap();
+        assert_eq!(cli.words, vec!["-".to_string()]);
+    }
+
+    #[test]
+    fn test_cli_default_output_is_pretty() {
+        let cli = Cli::try_parse_from(["sw", "hello"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Pretty);
+    }
+
+    #[test]
+    fn test_cli_with_output_flag() {
+        let cli = Cli::try_parse_from(["sw", "hello", "-o", "json"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_cli_with_cache_flags() {
+        let cli = Cli::try_parse_from(["sw", "hello", "--offline", "--refresh", "--cache-ttl", "7"]).unwrap();
+        assert!(cli.offline);
+        assert!(cli.refresh);
+        assert_eq!(cli.cache_ttl, Some(7));
+    }
+
+    #[test]
+    fn test_cli_cache_flags_default() {
+        let cli = Cli::try_parse_from(["sw", "hello"]).unwrap();
+        assert!(!cli.offline);
+        assert!(!cli.refresh);
+        assert_eq!(cli.cache_ttl, None);
+    }
+
+    #[test]
+    fn test_cli_lang_defaults_to_en() {
+        let cli = Cli::try_parse_from(["sw", "hello"]).unwrap();
+        assert_eq!(cli.lang, "en");
+        assert!(!cli.list_langs);
+    }
+
+    #[test]
+    fn test_cli_with_lang_flag() {
+        let cli = Cli::try_parse_from(["sw", "chat", "-l", "fr"]).unwrap();
+        assert_eq!(cli.lang, "fr");
+    }
+
+    #[test]
+    fn test_cli_with_list_langs_flag() {
+        let cli = Cli::try_parse_from(["sw", "--list-langs"]).unwrap();
+        assert!(cli.list_langs);
+    }
+
     // ==================== Exit Command Tests ====================
 
     #[test]
@@ -449,7 +759,7 @@ mod tests {
     #[tokio::test]
     #[ignore = "requires network access"]
     async fn test_lookup_word_success() {
-        let result = lookup_word("hello").await;
+        let result = lookup_word("hello", "en").await;
         assert!(result.is_ok());
         let definitions = result.unwrap();
         assert!(!definitions.is_empty());
@@ -459,7 +769,7 @@ mod tests {
     #[tokio::test]
     #[ignore = "requires network access"]
     async fn test_lookup_word_not_found() {
-        let result = lookup_word("asdfghjklqwerty123456").await;
+        let result = lookup_word("asdfghjklqwerty123456", "en").await;
         assert!(result.is_err());
     }
 }
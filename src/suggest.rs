@@ -0,0 +1,115 @@
+//! "Did you mean ...?" suggestions for words that aren't found in the dictionary.
+
+/// Bundled word list, one word per line, embedded at compile time.
+const WORD_LIST: &str = include_str!("../assets/words.txt");
+
+/// Number of suggestions to surface to the user.
+const MAX_SUGGESTIONS: usize = 5;
+const MIN_SUGGESTIONS: usize = 3;
+
+/// Levenshtein edit distance between `a` and `b`, computed with two rolling
+/// rows for O(min(n, m)) memory instead of the full DP matrix.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let (a, b) = if a.chars().count() <= b.chars().count() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=a.len()).collect();
+    let mut curr = vec![0; a.len() + 1];
+
+    for (j, &bc) in b.iter().enumerate() {
+        curr[0] = j + 1;
+        for (i, &ac) in a.iter().enumerate() {
+            let cost = if ac == bc { 0 } else { 1 };
+            curr[i + 1] = (prev[i + 1] + 1)
+                .min(curr[i] + 1)
+                .min(prev[i] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[a.len()]
+}
+
+/// Suggest the closest bundled words to `word` by edit distance.
+///
+/// Candidates whose length differs from `word` by more than the current
+/// best distance are skipped, since their distance can't possibly beat it.
+/// Returns between `MIN_SUGGESTIONS` and `MAX_SUGGESTIONS` matches, sorted
+/// by distance then alphabetically.
+pub fn suggest(word: &str) -> Vec<String> {
+    let word_len = word.chars().count() as isize;
+    let mut best = usize::MAX;
+    let mut scored: Vec<(usize, &str)> = Vec::new();
+
+    for candidate in WORD_LIST.lines() {
+        if candidate.is_empty() {
+            continue;
+        }
+
+        let len_diff = (candidate.chars().count() as isize - word_len).unsigned_abs();
+        if len_diff > best && scored.len() >= MIN_SUGGESTIONS {
+            continue;
+        }
+
+        let distance = levenshtein(word, candidate);
+        if distance < best {
+            best = distance;
+        }
+        scored.push((distance, candidate));
+    }
+
+    scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, w)| w.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein_identical() {
+        assert_eq!(levenshtein("hello", "hello"), 0);
+    }
+
+    #[test]
+    fn test_levenshtein_substitution() {
+        assert_eq!(levenshtein("cat", "car"), 1);
+    }
+
+    #[test]
+    fn test_levenshtein_insertion() {
+        assert_eq!(levenshtein("cat", "cats"), 1);
+    }
+
+    #[test]
+    fn test_levenshtein_empty() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn test_suggest_returns_close_matches() {
+        let suggestions = suggest("appel");
+        assert!(suggestions.contains(&"apple".to_string()));
+    }
+
+    #[test]
+    fn test_suggest_is_sorted_and_bounded() {
+        let suggestions = suggest("carss");
+        assert!(suggestions.len() >= MIN_SUGGESTIONS);
+        assert!(suggestions.len() <= MAX_SUGGESTIONS);
+        assert!(suggestions.windows(2).all(|pair| {
+            (levenshtein("carss", &pair[0]), &pair[0]) <= (levenshtein("carss", &pair[1]), &pair[1])
+        }));
+    }
+}